@@ -0,0 +1,62 @@
+//! Tunable game configuration, loaded from `config.json5` at startup.
+//!
+//! Anything a player might want to change without recompiling — grid
+//! size, window size, the speed curve, teleport mode, colors — lives
+//! here instead of as constants scattered through `core`/`game`. Missing
+//! fields, and a missing file entirely, fall back to `Config::default()`.
+
+use serde::Deserialize;
+
+const CONFIG_PATH: &str = "config.json5";
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+#[serde(default)]
+pub struct Config {
+    pub grid_size: i32,
+    pub window_width: u32,
+    pub window_height: u32,
+    pub base_speed: f64,
+    pub progressive_speed: bool,
+    pub progressive_multiplier: f64,
+    pub progressive_cap: f64,
+    pub allow_teleport: bool,
+    pub snake_color: [f32; 4],
+    pub apple_color: [f32; 4],
+    pub background_color: [f32; 4],
+    pub wall_color: [f32; 4],
+    pub wall_count: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            grid_size: 30,
+            window_width: 480,
+            window_height: 480,
+            base_speed: 1.0 / 8.0,
+            progressive_speed: true,
+            progressive_multiplier: 0.05,
+            progressive_cap: 3.0,
+            allow_teleport: false,
+            snake_color: [0.0, 0.0, 1.0, 1.0],
+            apple_color: [1.0, 0.0, 0.0, 1.0],
+            background_color: [1.0, 1.0, 1.0, 1.0],
+            wall_color: [0.3, 0.3, 0.3, 1.0],
+            wall_count: 10,
+        }
+    }
+}
+
+impl Config {
+    /// Loads `config.json5` from the working directory, falling back to
+    /// `Config::default()` if the file is missing or fails to parse.
+    pub fn load() -> Config {
+        match std::fs::read_to_string(CONFIG_PATH) {
+            Ok(contents) => json5::from_str(&contents).unwrap_or_else(|err| {
+                eprintln!("config.json5 failed to parse ({err}), using defaults");
+                Config::default()
+            }),
+            Err(_) => Config::default(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Rendering-agnostic snake simulation.
+//!
+//! Everything in here is the actual game — segments, apple, score,
+//! settings and the step function — and none of it knows about windowing,
+//! OpenGL or piston. A backend crate (`desktop`, `web`, …) owns input
+//! translation and drawing, and just calls into `CoreState`. That split
+//! is what makes the simulation unit-testable headlessly and portable to
+//! targets like wasm32 that can't link `opengl_graphics`.
+
+pub mod config;
+
+mod state;
+
+pub use state::{CoreState, Direction, GameSettings, GameState, RenderRect, Segment};
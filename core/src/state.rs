@@ -0,0 +1,517 @@
+use std::collections::VecDeque;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::config::Config;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Top-level screen the game is currently showing.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum GameState {
+    Title,
+    Playing,
+    Dead,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub struct Segment {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub struct GameSettings {
+    pub progressive_speed: bool,
+    pub allow_teleport: bool,
+}
+
+/// A single axis-aligned square a backend should draw, in grid (pixel)
+/// coordinates, plus the color to draw it in. This is the entire surface
+/// between the core and a renderer: turn these into whatever primitive
+/// the backend's graphics API wants.
+pub struct RenderRect {
+    pub x: i32,
+    pub y: i32,
+    pub size: i32,
+    pub color: [f32; 4],
+}
+
+pub struct CoreState {
+    pub segments: Vec<Segment>,
+    direction: Direction,
+    input_buffer: VecDeque<Direction>,
+    max_buffer_size: usize,
+    pub apple: Segment,
+    pub walls: Vec<Segment>,
+    pub size: i32,
+    pub score: u32,
+    pub state: GameState,
+    pub settings: GameSettings,
+    pub snake_color: [f32; 4],
+    pub apple_color: [f32; 4],
+    pub wall_color: [f32; 4],
+    wall_count: usize,
+    windowx: u32,
+    windowy: u32,
+    seed: u64,
+    rng: StdRng,
+    // Timing control for progressive speed
+    last_update_time: f64,
+    update_interval: f64,
+    base_speed: f64,
+    progressive_multiplier: f64,
+    progressive_cap: f64,
+}
+
+impl CoreState {
+    /// `seed` drives every random choice the core makes (walls, apple
+    /// spawns); the same seed always produces the same layout and apple
+    /// sequence, which is what makes a run reproducible.
+    pub fn new(config: &Config, seed: u64) -> CoreState {
+        let settings = GameSettings {
+            progressive_speed: config.progressive_speed,
+            allow_teleport: config.allow_teleport,
+        };
+
+        let mut core = CoreState {
+            segments: Vec::new(),
+            direction: Direction::Right,
+            input_buffer: VecDeque::new(),
+            max_buffer_size: 2,
+            apple: Segment { x: 0, y: 0 },
+            walls: Vec::new(),
+            size: config.grid_size,
+            score: 0,
+            state: GameState::Title,
+            settings,
+            snake_color: config.snake_color,
+            apple_color: config.apple_color,
+            wall_color: config.wall_color,
+            wall_count: config.wall_count,
+            windowx: config.window_width,
+            windowy: config.window_height,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            last_update_time: 0.0,
+            update_interval: 1.0 / 15.0, // Start slow (8 updates per second)
+            base_speed: config.base_speed,
+            progressive_multiplier: config.progressive_multiplier,
+            progressive_cap: config.progressive_cap,
+        };
+        core.reset();
+        core
+    }
+
+    /// Reinitializes a round in place: segments, direction, buffered
+    /// input, score, walls, apple and timing fields. Reseeds the RNG from
+    /// the original seed, so the same seed reproduces the same obstacle
+    /// layout and apple sequence every time it's played.
+    pub fn reset(&mut self) {
+        self.rng = StdRng::seed_from_u64(self.seed);
+        let size = self.size;
+
+        self.segments = vec![
+            Segment {
+                x: 5 * size,
+                y: 3 * size,
+            },
+            Segment {
+                x: 4 * size,
+                y: 3 * size,
+            },
+            Segment {
+                x: 3 * size,
+                y: 3 * size,
+            },
+        ];
+        self.direction = Direction::Right;
+        self.input_buffer.clear();
+        self.score = 0;
+        self.last_update_time = 0.0;
+
+        self.generate_walls();
+        self.apple = Segment { x: 0, y: 0 };
+        self.gen_apple_coords(self.windowx, self.windowy);
+    }
+
+    /// Scatters up to `wall_count` obstacle cells across the grid, avoiding
+    /// the snake's starting segments and a one-cell buffer around them so
+    /// the snake is never boxed in immediately at spawn.
+    ///
+    /// `wall_count` comes straight from `config.json5`, so it can't be
+    /// trusted to leave enough free cells: this clamps it to the number of
+    /// grid cells actually eligible for a wall rather than looping forever
+    /// trying to place more walls than the grid has room for.
+    fn generate_walls(&mut self) {
+        let grid_width = (self.windowx / self.size as u32) as i32;
+        let grid_height = (self.windowy / self.size as u32) as i32;
+
+        let mut eligible: Vec<Segment> = Vec::new();
+        for gy in 0..grid_height {
+            for gx in 0..grid_width {
+                let candidate = Segment {
+                    x: gx * self.size,
+                    y: gy * self.size,
+                };
+                let too_close_to_start = self.segments.iter().any(|s| {
+                    (s.x - candidate.x).abs() <= self.size && (s.y - candidate.y).abs() <= self.size
+                });
+                if !too_close_to_start {
+                    eligible.push(candidate);
+                }
+            }
+        }
+
+        let target = self.wall_count.min(eligible.len());
+        let mut walls: Vec<Segment> = Vec::with_capacity(target);
+        while walls.len() < target {
+            let index = self.rng.random_range(0..eligible.len());
+            let candidate = eligible.swap_remove(index);
+            walls.push(candidate);
+        }
+
+        self.walls = walls;
+    }
+
+    /// Title -> Playing.
+    pub fn start(&mut self) {
+        if self.state == GameState::Title {
+            self.state = GameState::Playing;
+        }
+    }
+
+    /// Dead -> Playing, via a fresh `reset`.
+    pub fn confirm_restart(&mut self) {
+        if self.state == GameState::Dead {
+            self.reset();
+            self.state = GameState::Playing;
+        }
+    }
+
+    /// Buffers a direction change the same way the desktop backend always
+    /// has: validated against whatever is already queued (or the current
+    /// direction if nothing is), then trimmed to `max_buffer_size`.
+    pub fn queue_direction(&mut self, new_dir: Direction) {
+        let validation_direction = self.input_buffer.back().copied().unwrap_or(self.direction);
+        if !check_directions(&validation_direction, new_dir) {
+            return;
+        }
+
+        self.input_buffer.push_back(new_dir);
+        if self.input_buffer.len() > self.max_buffer_size {
+            self.input_buffer.pop_front();
+        }
+    }
+
+    pub fn toggle_progressive_speed(&mut self) {
+        self.settings.progressive_speed = !self.settings.progressive_speed;
+    }
+
+    pub fn toggle_teleport(&mut self) {
+        self.settings.allow_teleport = !self.settings.allow_teleport;
+    }
+
+    pub fn get_current_speed_info(&self) -> (f64, bool) {
+        let current_interval = self.current_interval();
+        (1.0 / current_interval, self.settings.progressive_speed)
+    }
+
+    /// Update interval for the current score: fixed, or progressively
+    /// shorter (down to `progressive_cap`x) as the snake grows.
+    fn current_interval(&self) -> f64 {
+        if self.settings.progressive_speed {
+            let speed_multiplier = 1.0 + (self.score as f64 * self.progressive_multiplier);
+            let capped_multiplier = speed_multiplier.min(self.progressive_cap);
+            self.base_speed / capped_multiplier
+        } else {
+            self.update_interval
+        }
+    }
+
+    /// Advances the simulation by `dt` seconds. A no-op unless enough time
+    /// has accumulated to justify the next grid step. Only meaningful
+    /// while `state == Playing`; backends are expected to gate the call.
+    pub fn step(&mut self, dt: f64, windowx: u32, windowy: u32) {
+        self.last_update_time += dt;
+
+        let current_interval = self.current_interval();
+
+        if self.last_update_time < current_interval {
+            return;
+        }
+        self.last_update_time = 0.0;
+
+        if let Some(new_dir) = self.input_buffer.pop_front() {
+            if check_directions(&self.direction, new_dir) {
+                self.direction = new_dir;
+            }
+        }
+
+        if matches!(self.direction, Direction::Up) {
+            self.segments.insert(
+                0,
+                Segment {
+                    x: self.segments[0].x,
+                    y: self.segments[0].y - self.size,
+                },
+            );
+        }
+        if matches!(self.direction, Direction::Down) {
+            self.segments.insert(
+                0,
+                Segment {
+                    x: self.segments[0].x,
+                    y: self.segments[0].y + self.size,
+                },
+            );
+        }
+        if matches!(self.direction, Direction::Left) {
+            self.segments.insert(
+                0,
+                Segment {
+                    x: self.segments[0].x - self.size,
+                    y: self.segments[0].y,
+                },
+            );
+        }
+        if matches!(self.direction, Direction::Right) {
+            self.segments.insert(
+                0,
+                Segment {
+                    x: self.segments[0].x + self.size,
+                    y: self.segments[0].y,
+                },
+            );
+        }
+
+        if self.settings.allow_teleport {
+            self.wrap_head(windowx, windowy);
+        }
+
+        if self.check_if_collision(windowx, windowy) {
+            self.state = GameState::Dead;
+            return;
+        }
+
+        if self.segments[0].x == self.apple.x && self.segments[0].y == self.apple.y {
+            self.gen_apple_coords(windowx, windowy);
+            self.score += 1;
+        } else {
+            self.segments.pop();
+        }
+    }
+
+    /// Wraps the head back onto the grid when it crosses a boundary,
+    /// turning the walls from lethal into toroidal.
+    fn wrap_head(&mut self, windowx: u32, windowy: u32) {
+        let grid_width = (windowx / self.size as u32) as i32;
+        let grid_height = (windowy / self.size as u32) as i32;
+
+        let head = &mut self.segments[0];
+        head.x = (head.x / self.size).rem_euclid(grid_width) * self.size;
+        head.y = (head.y / self.size).rem_euclid(grid_height) * self.size;
+    }
+
+    fn check_if_collision(&self, windowx: u32, windowy: u32) -> bool {
+        let head = &self.segments[0];
+
+        // Boundaries are never lethal in teleport mode; self- and
+        // wall-collision still are.
+        if !self.settings.allow_teleport
+            && (head.x < 0 || head.y < 0 || head.x as u32 >= windowx || head.y as u32 >= windowy)
+        {
+            return true;
+        }
+
+        self.segments[1..].contains(head) || self.walls.contains(head)
+    }
+
+    /// Picks a new apple cell, avoiding the snake, the walls, and the
+    /// apple's own current cell.
+    ///
+    /// Collects the eligible cells up front rather than rejection-sampling
+    /// in a loop: with `wall_count` set high enough (it's user-editable via
+    /// `config.json5`) there may be no free cell left at all, which would
+    /// otherwise spin forever. When that happens the apple simply stays put.
+    fn gen_apple_coords(&mut self, windowx: u32, windowy: u32) {
+        let grid_width = (windowx / self.size as u32) as i32;
+        let grid_height = (windowy / self.size as u32) as i32;
+
+        let mut eligible: Vec<Segment> = Vec::new();
+        for gy in 0..grid_height {
+            for gx in 0..grid_width {
+                let candidate = Segment {
+                    x: gx * self.size,
+                    y: gy * self.size,
+                };
+                if !self.segments.contains(&candidate)
+                    && !self.walls.contains(&candidate)
+                    && candidate != self.apple
+                {
+                    eligible.push(candidate);
+                }
+            }
+        }
+
+        if let Some(&candidate) = eligible.get(self.rng.random_range(0..eligible.len().max(1))) {
+            self.apple = candidate;
+        }
+    }
+
+    /// Flattens the current frame into plain rectangles + colors so a
+    /// backend can hand them straight to its renderer without reaching
+    /// into simulation internals.
+    pub fn render_rects(&self) -> Vec<RenderRect> {
+        let mut rects: Vec<RenderRect> = self
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                // Head (index 0) is darkest, tail is lightest.
+                let gradient_factor = 1.0 - (index as f32 * 0.7 / self.segments.len() as f32);
+                let color = [
+                    self.snake_color[0],
+                    self.snake_color[1],
+                    self.snake_color[2],
+                    gradient_factor.max(0.7),
+                ];
+                RenderRect {
+                    x: segment.x,
+                    y: segment.y,
+                    size: self.size,
+                    color,
+                }
+            })
+            .collect();
+
+        for wall in &self.walls {
+            rects.push(RenderRect {
+                x: wall.x,
+                y: wall.y,
+                size: self.size,
+                color: self.wall_color,
+            });
+        }
+
+        rects.push(RenderRect {
+            x: self.apple.x,
+            y: self.apple.y,
+            size: self.size,
+            color: self.apple_color,
+        });
+
+        rects
+    }
+}
+
+fn check_directions(dir1: &Direction, dir2: Direction) -> bool {
+    if (matches!(dir1, Direction::Down) && matches!(dir2, Direction::Up))
+        || (matches!(dir1, Direction::Up) && matches!(dir2, Direction::Down))
+        || (matches!(dir1, Direction::Left) && matches!(dir2, Direction::Right))
+        || (matches!(dir1, Direction::Right) && matches!(dir2, Direction::Left))
+    {
+        return false;
+    }
+    true
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn same_seed_reproduces_walls_and_apple() {
+        let config = Config::default();
+        let a = CoreState::new(&config, 42);
+        let b = CoreState::new(&config, 42);
+
+        assert_eq!(a.walls.len(), b.walls.len());
+        for (wa, wb) in a.walls.iter().zip(b.walls.iter()) {
+            assert_eq!((wa.x, wa.y), (wb.x, wb.y));
+        }
+        assert_eq!((a.apple.x, a.apple.y), (b.apple.x, b.apple.y));
+    }
+
+    #[test]
+    fn restart_reseeds_to_the_same_layout() {
+        let config = Config::default();
+        let mut state = CoreState::new(&config, 7);
+        let walls_before = state.walls.clone();
+        let apple_before = state.apple;
+
+        state.state = GameState::Dead;
+        state.confirm_restart();
+
+        assert_eq!(walls_before.len(), state.walls.len());
+        for (before, after) in walls_before.iter().zip(state.walls.iter()) {
+            assert_eq!((before.x, before.y), (after.x, after.y));
+        }
+        assert_eq!((apple_before.x, apple_before.y), (state.apple.x, state.apple.y));
+    }
+
+    #[test]
+    fn wall_count_is_clamped_to_available_free_cells() {
+        let config = Config {
+            grid_size: 30,
+            window_width: 60,
+            window_height: 30,
+            wall_count: 1000,
+            ..Config::default()
+        };
+
+        let state = CoreState::new(&config, 1);
+
+        // Only a 2x1 grid exists, so `wall_count: 1000` must be clamped
+        // down to however many cells are actually eligible, not hang.
+        assert!(state.walls.len() <= 2);
+    }
+
+    #[test]
+    fn hitting_a_wall_kills_the_snake() {
+        let config = Config::default();
+        let mut state = CoreState::new(&config, 1);
+
+        let head = state.segments[0];
+        state.walls = vec![Segment {
+            x: head.x + state.size,
+            y: head.y,
+        }];
+        state.direction = Direction::Right;
+        state.last_update_time = 1000.0;
+
+        state.step(0.0, config.window_width, config.window_height);
+
+        assert_eq!(state.state, GameState::Dead);
+    }
+
+    #[test]
+    fn teleport_wraps_the_head_instead_of_killing() {
+        let config = Config {
+            allow_teleport: true,
+            wall_count: 0,
+            ..Config::default()
+        };
+        let mut state = CoreState::new(&config, 1);
+
+        let grid_width = (config.window_width / config.grid_size as u32) as i32;
+        let edge_x = (grid_width - 1) * config.grid_size;
+        state.segments = vec![Segment { x: edge_x, y: 0 }];
+        state.direction = Direction::Right;
+        state.walls.clear();
+        state.last_update_time = 1000.0;
+
+        state.step(0.0, config.window_width, config.window_height);
+
+        assert_eq!(state.segments[0].x, 0);
+        assert_ne!(state.state, GameState::Dead);
+    }
+}
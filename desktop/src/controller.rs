@@ -0,0 +1,116 @@
+//! Gamepad/controller input, layered on top of the existing keyboard
+//! handling rather than replacing it: both paths resolve to the same
+//! `core::Direction` and go through the same `CoreState::queue_direction`
+//! validation, so a player can freely mix keyboard and controller input.
+//! Requires the `sdl2_window` backend in `main.rs` — piston's glutin
+//! backend never polls or reports gamepads, so these events would
+//! otherwise never fire.
+//!
+//! D-pad buttons map straight to a direction. The left stick is
+//! dead-zoned and quantized to whichever of the four directions its
+//! dominant axis points toward, since the core only understands grid-
+//! aligned movement.
+
+use std::collections::HashMap;
+
+use piston::input::{ControllerAxisArgs, ControllerButton};
+
+use gym_snake_core::Direction;
+
+// Typical SDL2-style gamepad mapping (e.g. Xbox layout): D-pad buttons and
+// the left stick's two axes.
+const DPAD_UP: u8 = 11;
+const DPAD_DOWN: u8 = 12;
+const DPAD_LEFT: u8 = 13;
+const DPAD_RIGHT: u8 = 14;
+const LEFT_STICK_X: u8 = 0;
+const LEFT_STICK_Y: u8 = 1;
+
+const STICK_DEAD_ZONE: f64 = 0.35;
+
+/// Piston doesn't surface a dedicated controller-disconnect event, so a
+/// controller is treated as gone once this many frames pass without any
+/// button or axis event from its id.
+const STALE_AFTER_FRAMES: u64 = 300;
+
+pub struct ControllerManager {
+    // Last known (x, y) stick position per controller id, so a single
+    // axis event (which only carries one axis) can still be quantized
+    // against the other axis's last known value.
+    stick_position: HashMap<u32, (f64, f64)>,
+    // Frame number each controller id was last heard from, so a
+    // disconnected controller's state can be cleaned up without a
+    // dedicated disconnect event to trigger on.
+    last_seen_frame: HashMap<u32, u64>,
+}
+
+impl ControllerManager {
+    pub fn new() -> ControllerManager {
+        ControllerManager {
+            stick_position: HashMap::new(),
+            last_seen_frame: HashMap::new(),
+        }
+    }
+
+    /// Maps a D-pad button press to a direction, if it's one we recognize.
+    pub fn direction_for_button(
+        &mut self,
+        button: ControllerButton,
+        frame: u64,
+    ) -> Option<Direction> {
+        self.last_seen_frame.insert(button.id, frame);
+        match button.button {
+            DPAD_UP => Some(Direction::Up),
+            DPAD_DOWN => Some(Direction::Down),
+            DPAD_LEFT => Some(Direction::Left),
+            DPAD_RIGHT => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Updates the cached stick position for `args` and, if it now sits
+    /// outside the dead zone, returns the direction the dominant axis
+    /// points toward.
+    pub fn direction_for_axis(&mut self, args: &ControllerAxisArgs, frame: u64) -> Option<Direction> {
+        self.last_seen_frame.insert(args.id, frame);
+
+        let position = self.stick_position.entry(args.id).or_insert((0.0, 0.0));
+        match args.axis {
+            LEFT_STICK_X => position.0 = args.position,
+            LEFT_STICK_Y => position.1 = args.position,
+            _ => return None,
+        }
+        let (x, y) = *position;
+
+        if x.abs() < STICK_DEAD_ZONE && y.abs() < STICK_DEAD_ZONE {
+            return None;
+        }
+
+        if x.abs() > y.abs() {
+            Some(if x > 0.0 { Direction::Right } else { Direction::Left })
+        } else {
+            Some(if y > 0.0 { Direction::Down } else { Direction::Up })
+        }
+    }
+
+    /// Drops state for any controller not heard from in `STALE_AFTER_FRAMES`
+    /// frames, so a stale reading can't produce a phantom direction if the
+    /// same `id` is reused by a controller that reconnects later.
+    pub fn expire_stale(&mut self, frame: u64) {
+        let stale: Vec<u32> = self
+            .last_seen_frame
+            .iter()
+            .filter(|(_, &last_seen)| frame.saturating_sub(last_seen) > STALE_AFTER_FRAMES)
+            .map(|(&id, _)| id)
+            .collect();
+
+        for id in stale {
+            self.forget(id);
+        }
+    }
+
+    fn forget(&mut self, id: u32) {
+        self.stick_position.remove(&id);
+        self.last_seen_frame.remove(&id);
+    }
+}
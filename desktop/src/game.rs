@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+
+use opengl_graphics::{GlGraphics, GlyphCache, TextureSettings};
+use piston::input::{Button, ButtonArgs, ButtonState, ControllerAxisArgs, Key, RenderArgs, UpdateArgs};
+
+use gym_snake_core::config::Config;
+use gym_snake_core::{CoreState, Direction, GameState};
+
+use crate::controller::ControllerManager;
+
+pub use gym_snake_core::Segment;
+
+/// Key used to confirm a transition (start from Title, restart from Dead).
+const CONFIRM_KEY: Key = Key::Return;
+
+/// Bundled HUD font, relative to the crate so it's found regardless of
+/// the process's working directory. DejaVu Sans ships under the
+/// permissive Bitstream Vera license (see assets/DejaVuSans-LICENSE.txt).
+const FONT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/DejaVuSans.ttf");
+
+/// Desktop backend: owns the `GlGraphics` context and the piston input
+/// state, and drives `CoreState` — the actual simulation — from them.
+/// Nothing in `core` knows piston exists; this is the only place that
+/// translates between the two.
+pub struct Game {
+    gl: GlGraphics,
+    glyphs: GlyphCache<'static>,
+    core: CoreState,
+    background_color: [f32; 4],
+    controller: ControllerManager,
+    // Frame counter and edge-detection so a held key can't immediately
+    // re-trigger a state transition (e.g. the key that confirms a restart).
+    frame_count: u64,
+    pressed: HashSet<Key>,
+    prev_pressed: HashSet<Key>,
+}
+
+impl Game {
+    pub fn new(gl: GlGraphics, config: &Config, seed: u64) -> Game {
+        let glyphs = GlyphCache::new(FONT_PATH, (), TextureSettings::new())
+            .expect("failed to load bundled HUD font");
+
+        Game {
+            gl,
+            glyphs,
+            core: CoreState::new(config, seed),
+            background_color: config.background_color,
+            controller: ControllerManager::new(),
+            frame_count: 0,
+            pressed: HashSet::new(),
+            prev_pressed: HashSet::new(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.core.reset();
+    }
+
+    pub fn score(&self) -> u32 {
+        self.core.score
+    }
+
+    pub fn render(&mut self, args: &RenderArgs) {
+        match self.core.state {
+            GameState::Title => self.render_title(args),
+            GameState::Playing => self.render_playing(args),
+            GameState::Dead => self.render_dead(args),
+        }
+    }
+
+    fn render_title(&mut self, args: &RenderArgs) {
+        use graphics::*;
+
+        const GRAY: [f32; 4] = [0.2, 0.2, 0.2, 1.0];
+        const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+        let size = self.core.size as f64;
+        let center_x = args.window_size[0] / 2.0 - size / 2.0;
+        let center_y = args.window_size[1] / 2.0 - size / 2.0;
+        let marker = rectangle::square(center_x, center_y, size);
+
+        let title_x = args.window_size[0] / 2.0 - 70.0;
+        let title_y = center_y - 30.0;
+        let prompt_x = args.window_size[0] / 2.0 - 100.0;
+        let prompt_y = center_y + size + 30.0;
+        let glyphs = &mut self.glyphs;
+
+        self.gl.draw(args.viewport(), |c, gl| {
+            clear(GRAY, gl);
+            let transform = c.transform.trans(0.0, 0.0);
+            rectangle(WHITE, marker, transform, gl);
+
+            Text::new_color(WHITE, 24)
+                .draw(
+                    "SNAKE",
+                    glyphs,
+                    &c.draw_state,
+                    c.transform.trans(title_x, title_y),
+                    gl,
+                )
+                .ok();
+
+            Text::new_color(WHITE, 16)
+                .draw(
+                    "PRESS ENTER TO START",
+                    glyphs,
+                    &c.draw_state,
+                    c.transform.trans(prompt_x, prompt_y),
+                    gl,
+                )
+                .ok();
+        });
+    }
+
+    fn render_playing(&mut self, args: &RenderArgs) {
+        use graphics::*;
+
+        const HUD_COLOR: [f32; 4] = [0.1, 0.1, 0.1, 1.0];
+
+        let background = self.background_color;
+        let rects = self.core.render_rects();
+        let (current_ups, progressive) = self.core.get_current_speed_info();
+        let score = self.core.score;
+        let hud_text = format!(
+            "Score: {}   {:.0} UPS{}",
+            score,
+            current_ups,
+            if progressive { " (progressive)" } else { "" }
+        );
+        let glyphs = &mut self.glyphs;
+
+        self.gl.draw(args.viewport(), |c, gl| {
+            clear(background, gl);
+            let transform = c.transform.trans(0.0, 0.0).rot_deg(0.0);
+
+            for rect in &rects {
+                let square = rectangle::square(rect.x as f64, rect.y as f64, rect.size as f64);
+                rectangle(rect.color, square, transform, gl);
+            }
+
+            let hud_transform = c.transform.trans(8.0, 20.0);
+            Text::new_color(HUD_COLOR, 16)
+                .draw(&hud_text, glyphs, &c.draw_state, hud_transform, gl)
+                .ok();
+        });
+    }
+
+    fn render_dead(&mut self, args: &RenderArgs) {
+        use graphics::*;
+
+        const DIM: [f32; 4] = [0.0, 0.0, 0.0, 0.5];
+        const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+        self.render_playing(args);
+
+        let center_x = args.window_size[0] / 2.0;
+        let center_y = args.window_size[1] / 2.0;
+        let glyphs = &mut self.glyphs;
+
+        self.gl.draw(args.viewport(), |c, gl| {
+            let transform = c.transform.trans(0.0, 0.0);
+            let overlay = [0.0, 0.0, args.window_size[0], args.window_size[1]];
+            rectangle(DIM, overlay, transform, gl);
+
+            let game_over_transform = c.transform.trans(center_x - 70.0, center_y - 10.0);
+            Text::new_color(WHITE, 24)
+                .draw("GAME OVER", glyphs, &c.draw_state, game_over_transform, gl)
+                .ok();
+
+            let prompt_transform = c.transform.trans(center_x - 100.0, center_y + 20.0);
+            Text::new_color(WHITE, 16)
+                .draw(
+                    "PRESS ENTER TO RESTART",
+                    glyphs,
+                    &c.draw_state,
+                    prompt_transform,
+                    gl,
+                )
+                .ok();
+        });
+    }
+
+    pub fn update(&mut self, args: &UpdateArgs, windowx: &u32, windowy: &u32) {
+        self.frame_count += 1;
+        self.controller.expire_stale(self.frame_count);
+        let just_pressed: HashSet<Key> =
+            self.pressed.difference(&self.prev_pressed).copied().collect();
+
+        match self.core.state {
+            GameState::Title => {
+                if just_pressed.contains(&CONFIRM_KEY) {
+                    self.core.start();
+                }
+            }
+            GameState::Playing => self.core.step(args.dt, *windowx, *windowy),
+            GameState::Dead => {
+                if just_pressed.contains(&CONFIRM_KEY) {
+                    self.core.confirm_restart();
+                }
+            }
+        }
+
+        self.prev_pressed = self.pressed.clone();
+    }
+
+    pub fn change_directions(&mut self, args: &ButtonArgs) {
+        // Track the held-key set regardless of state; `update` diffs it
+        // against the previous frame to edge-detect the confirm key.
+        if let Button::Keyboard(key) = args.button {
+            match args.state {
+                ButtonState::Press => {
+                    self.pressed.insert(key);
+                }
+                ButtonState::Release => {
+                    self.pressed.remove(&key);
+                }
+            }
+        }
+
+        if self.core.state != GameState::Playing {
+            return;
+        }
+
+        if args.state == ButtonState::Press {
+            let pressed_direction = match args.button {
+                Button::Keyboard(Key::Up) => Some(Direction::Up),
+                Button::Keyboard(Key::Down) => Some(Direction::Down),
+                Button::Keyboard(Key::Left) => Some(Direction::Left),
+                Button::Keyboard(Key::Right) => Some(Direction::Right),
+                Button::Controller(controller_button) => self
+                    .controller
+                    .direction_for_button(controller_button, self.frame_count),
+                _ => None,
+            };
+
+            if let Button::Keyboard(Key::P) = args.button {
+                self.toggle_progressive_speed();
+                return;
+            }
+
+            if let Button::Keyboard(Key::T) = args.button {
+                self.core.toggle_teleport();
+                return;
+            }
+
+            if let Some(p_dir) = pressed_direction {
+                self.core.queue_direction(p_dir);
+            }
+        }
+    }
+
+    /// Handles analog-stick motion; piston reports this as its own event
+    /// type rather than through `ButtonArgs`.
+    pub fn handle_controller_axis(&mut self, args: &ControllerAxisArgs) {
+        if self.core.state != GameState::Playing {
+            return;
+        }
+
+        if let Some(direction) = self.controller.direction_for_axis(args, self.frame_count) {
+            self.core.queue_direction(direction);
+        }
+    }
+
+    pub fn toggle_progressive_speed(&mut self) {
+        self.core.toggle_progressive_speed();
+    }
+
+    pub fn get_current_speed_info(&self) -> (f64, bool) {
+        self.core.get_current_speed_info()
+    }
+}
@@ -1,31 +1,46 @@
-extern crate glutin_window;
 extern crate graphics;
 extern crate opengl_graphics;
 extern crate piston;
 extern crate rand;
+extern crate sdl2_window;
 
 use crate::piston::EventLoop;
-use glutin_window::GlutinWindow as Window;
 use opengl_graphics::{GlGraphics, OpenGL};
+use sdl2_window::Sdl2Window as Window;
 use piston::event_loop::{EventSettings, Events};
-use piston::input::{
-    Button, ButtonArgs, ButtonEvent, ButtonState, Key, RenderArgs, RenderEvent, UpdateArgs,
-    UpdateEvent,
-};
+use piston::input::{ButtonEvent, ControllerAxisEvent, RenderEvent, UpdateEvent};
 use piston::window::WindowSettings;
 use rand::Rng;
 
+mod controller;
 pub mod game;
 
-use game::{Game, Segment};
+use gym_snake_core::config::Config;
+
+use game::Game;
+
+/// Reads `--seed <u64>` off the command line; falls back to a randomly
+/// chosen seed (printed below) if it's absent or malformed.
+fn parse_seed_arg() -> Option<u64> {
+    let args: Vec<String> = std::env::args().collect();
+    let index = args.iter().position(|arg| arg == "--seed")?;
+    args.get(index + 1)?.parse().ok()
+}
 
 fn main() {
     println!("Hello, world!");
 
+    let config = Config::load();
+    let seed = parse_seed_arg().unwrap_or_else(|| rand::rng().random());
+    println!("Using seed: {seed} (pass --seed {seed} to reproduce this run)");
+
     let opengl = OpenGL::V3_2;
-    let windowx: u32 = 480;
-    let windowy: u32 = 480;
+    let windowx: u32 = config.window_width;
+    let windowy: u32 = config.window_height;
 
+    // sdl2_window, not glutin_window: piston's glutin backend never polls
+    // or reports gamepads, so Button::Controller/ControllerAxisArgs would
+    // never fire. SDL2 does surface them.
     let mut window: Window = WindowSettings::new("Snake Game", [windowx, windowy])
         .graphics_api(opengl)
         .exit_on_esc(true)
@@ -34,7 +49,7 @@ fn main() {
         .unwrap();
 
     let gl = GlGraphics::new(opengl);
-    let mut game = Game::new(gl);
+    let mut game = Game::new(gl, &config, seed);
 
     // Use higher UPS for smooth timing, game will control its own speed internally
     let event_settings = EventSettings::new().ups(60);
@@ -42,38 +57,18 @@ fn main() {
 
     while let Some(event) = events.next(&mut window) {
         if let Some(args) = event.render_args() {
-            // println!("Rendering...");
-
             game.render(&args);
         }
         if let Some(args) = event.update_args() {
-            // Get speed info before update
-            let (current_ups, progressive_enabled) = game.get_current_speed_info();
-
-            if progressive_enabled {
-                println!(
-                    "Updating... Score: {}, Speed: {:.1} UPS (Progressive)",
-                    game.score, current_ups
-                );
-            } else {
-                println!(
-                    "Updating... Score: {}, Speed: {:.1} UPS (Fixed)",
-                    game.score, current_ups
-                );
-            }
-
             game.update(&args, &windowx, &windowy);
         }
 
-        if game.game_over {
-            println!("Game Over! Your score: {}", game.score);
-            break;
-        }
-
         if let Some(args) = event.button_args() {
-            println!("new input: {:?}", &args);
-
             game.change_directions(&args);
         }
+
+        if let Some(args) = event.controller_axis_args() {
+            game.handle_controller_axis(&args);
+        }
     }
 }
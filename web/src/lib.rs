@@ -0,0 +1,85 @@
+//! Minimal WebAssembly backend.
+//!
+//! Drives `gym_snake_core::CoreState` from the browser instead of from a
+//! piston event loop: the browser's `requestAnimationFrame` callback
+//! calls `tick`, its `keydown` handler calls `on_key_down`, and a
+//! `<canvas>` renderer (see `www/index.js`) reads back `render_rects`
+//! each frame. Built with `wasm-pack build --target web`; see `index.html`
+//! for the harness that loads the resulting module.
+
+use wasm_bindgen::prelude::*;
+
+use gym_snake_core::config::Config;
+use gym_snake_core::{CoreState, Direction, GameState};
+
+#[wasm_bindgen]
+pub struct WebGame {
+    core: CoreState,
+    windowx: u32,
+    windowy: u32,
+}
+
+#[wasm_bindgen]
+impl WebGame {
+    /// Builds a game using the default config; the web build has no
+    /// filesystem to read `config.json5` from. `seed` drives the wall
+    /// layout and apple sequence the same way it does on desktop.
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: u64) -> WebGame {
+        let config = Config::default();
+        WebGame {
+            windowx: config.window_width,
+            windowy: config.window_height,
+            core: CoreState::new(&config, seed),
+        }
+    }
+
+    /// Advances the simulation by `dt` seconds. A no-op outside Playing.
+    pub fn tick(&mut self, dt: f64) {
+        if self.core.state == GameState::Playing {
+            self.core.step(dt, self.windowx, self.windowy);
+        }
+    }
+
+    /// Takes a JS `KeyboardEvent.key` value and feeds it through the same
+    /// direction/confirm handling the desktop backend uses.
+    pub fn on_key_down(&mut self, key: &str) {
+        match key {
+            "ArrowUp" => self.core.queue_direction(Direction::Up),
+            "ArrowDown" => self.core.queue_direction(Direction::Down),
+            "ArrowLeft" => self.core.queue_direction(Direction::Left),
+            "ArrowRight" => self.core.queue_direction(Direction::Right),
+            "Enter" => match self.core.state {
+                GameState::Title => self.core.start(),
+                GameState::Dead => self.core.confirm_restart(),
+                GameState::Playing => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Flattened `[x, y, size, r, g, b, a]` tuples, one per rect, for the
+    /// JS canvas renderer — mirrors the rectangles the desktop backend
+    /// hands to `opengl_graphics`.
+    pub fn render_rects(&self) -> Vec<f64> {
+        self.core
+            .render_rects()
+            .into_iter()
+            .flat_map(|r| {
+                [
+                    r.x as f64,
+                    r.y as f64,
+                    r.size as f64,
+                    r.color[0] as f64,
+                    r.color[1] as f64,
+                    r.color[2] as f64,
+                    r.color[3] as f64,
+                ]
+            })
+            .collect()
+    }
+
+    pub fn score(&self) -> u32 {
+        self.core.score
+    }
+}